@@ -53,6 +53,47 @@ where
         self.value.to_data()
     }
 
+    /// Returns the single element held by this tensor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tensor does not have exactly one element.
+    pub fn into_scalar(self) -> B::Elem {
+        let data = self.into_data();
+        assert_eq!(
+            data.value.len(),
+            1,
+            "into_scalar: tensor must have exactly one element, got shape {:?}",
+            data.shape.dims
+        );
+
+        data.value.into_iter().next().unwrap()
+    }
+
+    /// Returns the element at `index` without materializing the full host buffer beyond a
+    /// single read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index[i] >= self.shape().dims[i]` for any axis `i`.
+    pub fn value_at(&self, index: [usize; D]) -> B::Elem {
+        let dims = self.shape().dims;
+        for i in 0..D {
+            assert!(
+                index[i] < dims[i],
+                "value_at: index {} out of bounds for axis {} (size {})",
+                index[i],
+                i,
+                dims[i]
+            );
+        }
+
+        let ranges: Vec<_> = index.iter().map(|&i| i..i + 1).collect();
+        let ranges: [std::ops::Range<usize>; D] = ranges.try_into().unwrap();
+
+        self.index(ranges).into_scalar()
+    }
+
     pub fn zeros_like(&self) -> Self {
         Tensor::new(B::zeros(self.shape().clone(), self.value.device()))
     }
@@ -275,6 +316,1129 @@ where
     }
 }
 
+/// Configuration for a convolution operation over `N` spatial dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvOptions<const N: usize> {
+    /// Stride of the kernel along each spatial dimension.
+    pub stride: [usize; N],
+    /// Zero-padding added to both sides of each spatial dimension.
+    pub padding: [usize; N],
+    /// Spacing between kernel elements along each spatial dimension.
+    pub dilation: [usize; N],
+    /// Number of blocked connections from input channels to output channels.
+    pub groups: usize,
+}
+
+impl<const N: usize> ConvOptions<N> {
+    /// Creates a new set of convolution options.
+    pub fn new(
+        stride: [usize; N],
+        padding: [usize; N],
+        dilation: [usize; N],
+        groups: usize,
+    ) -> Self {
+        Self {
+            stride,
+            padding,
+            dilation,
+            groups,
+        }
+    }
+}
+
+/// Computes the output size of a single spatial dimension of a convolution.
+fn conv_output_size(
+    size: usize,
+    kernel: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+) -> usize {
+    (size + 2 * padding - dilation * (kernel - 1) - 1) / stride + 1
+}
+
+/// Like [`Tensor::zeros`], but allocates on `device` instead of `B::Device::default()`, so the
+/// result stays on the same device as the tensor it is derived from.
+fn zeros_on<B: Backend, const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+    Tensor::new(B::zeros(shape, device))
+}
+
+/// Like [`Tensor::ones`], but allocates on `device` instead of `B::Device::default()`.
+fn ones_on<B: Backend, const D: usize>(shape: Shape<D>, device: B::Device) -> Tensor<B, D> {
+    Tensor::new(B::ones(shape, device))
+}
+
+impl<B: Backend> Tensor<B, 3> {
+    /// Applies a 1D convolution.
+    ///
+    /// * `x` - shape `[batch_size, channels_in, length]`.
+    /// * `weight` - shape `[channels_out, channels_in / groups, kernel_size]`.
+    /// * `bias` - optional shape `[channels_out]`.
+    ///
+    /// The default implementation lowers the convolution to an im2col gather followed by a
+    /// per-group `matmul`, so it works on any backend without a dedicated kernel.
+    pub fn conv1d(
+        &self,
+        weight: &Self,
+        bias: Option<&Tensor<B, 1>>,
+        options: ConvOptions<1>,
+    ) -> Self {
+        let [batch_size, channels_in, length] = self.shape().dims;
+        let [channels_out, channels_in_per_group, kernel_size] = weight.shape().dims;
+        let groups = options.groups;
+
+        assert_eq!(
+            channels_in,
+            channels_in_per_group * groups,
+            "conv1d: input channels must equal weight's channels_in_per_group * groups"
+        );
+        assert_eq!(
+            channels_out % groups,
+            0,
+            "conv1d: weight's channels_out must be evenly divisible by groups"
+        );
+
+        let padded = self.pad1d(options.padding[0]);
+        let length_out = conv_output_size(
+            length,
+            kernel_size,
+            options.stride[0],
+            options.padding[0],
+            options.dilation[0],
+        );
+        let channels_out_per_group = channels_out / groups;
+
+        let mut group_outputs = Vec::with_capacity(groups);
+        for g in 0..groups {
+            let input_group = padded.index([
+                0..batch_size,
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..padded.shape().dims[2],
+            ]);
+            let weight_group = weight
+                .index([
+                    g * channels_out_per_group..(g + 1) * channels_out_per_group,
+                    0..channels_in_per_group,
+                    0..kernel_size,
+                ])
+                .reshape(Shape::new([
+                    channels_out_per_group,
+                    channels_in_per_group * kernel_size,
+                ]));
+
+            let columns = input_group.im2col1d(
+                kernel_size,
+                options.stride[0],
+                options.dilation[0],
+                length_out,
+            );
+            group_outputs.push(matmul_columns1d(
+                &weight_group,
+                &columns,
+                batch_size,
+                channels_out_per_group,
+                [length_out],
+            ));
+        }
+
+        let mut output = Tensor::cat(group_outputs, 1);
+
+        if let Some(bias) = bias {
+            output = output.add(&broadcast_bias1d(
+                bias,
+                batch_size,
+                channels_out,
+                [length_out],
+            ));
+        }
+
+        output
+    }
+
+    fn pad1d(&self, padding: usize) -> Self {
+        if padding == 0 {
+            return self.clone();
+        }
+
+        let [batch_size, channels, length] = self.shape().dims;
+        let padded = zeros_on(
+            Shape::new([batch_size, channels, length + 2 * padding]),
+            self.value.device(),
+        );
+
+        padded.index_assign(
+            [0..batch_size, 0..channels, padding..padding + length],
+            self,
+        )
+    }
+
+    fn im2col1d(
+        &self,
+        kernel_size: usize,
+        stride: usize,
+        dilation: usize,
+        length_out: usize,
+    ) -> Tensor<B, 3> {
+        let [batch_size, channels, _] = self.shape().dims;
+        let mut patches = Vec::with_capacity(kernel_size);
+
+        for k in 0..kernel_size {
+            let mut columns = Vec::with_capacity(length_out);
+            for o in 0..length_out {
+                let i = o * stride + k * dilation;
+                columns.push(
+                    self.index([0..batch_size, 0..channels, i..i + 1])
+                        .reshape(Shape::new([batch_size, channels, 1])),
+                );
+            }
+            let column = Tensor::cat(columns, 2);
+            patches.push(column.reshape(Shape::new([batch_size, channels, 1, length_out])));
+        }
+
+        Tensor::cat(patches, 2).reshape(Shape::new([
+            batch_size,
+            channels * kernel_size,
+            length_out,
+        ]))
+    }
+}
+
+impl<B: Backend> Tensor<B, 4> {
+    /// Applies a 2D convolution.
+    ///
+    /// * `x` - shape `[batch_size, channels_in, height, width]`.
+    /// * `weight` - shape `[channels_out, channels_in / groups, kernel_h, kernel_w]`.
+    /// * `bias` - optional shape `[channels_out]`.
+    pub fn conv2d(
+        &self,
+        weight: &Self,
+        bias: Option<&Tensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> Self {
+        let [batch_size, channels_in, height, width] = self.shape().dims;
+        let [channels_out, channels_in_per_group, kernel_h, kernel_w] = weight.shape().dims;
+        let groups = options.groups;
+
+        assert_eq!(
+            channels_in,
+            channels_in_per_group * groups,
+            "conv2d: input channels must equal weight's channels_in_per_group * groups"
+        );
+        assert_eq!(
+            channels_out % groups,
+            0,
+            "conv2d: weight's channels_out must be evenly divisible by groups"
+        );
+
+        let padded = self.pad2d(options.padding);
+        let height_out = conv_output_size(
+            height,
+            kernel_h,
+            options.stride[0],
+            options.padding[0],
+            options.dilation[0],
+        );
+        let width_out = conv_output_size(
+            width,
+            kernel_w,
+            options.stride[1],
+            options.padding[1],
+            options.dilation[1],
+        );
+        let channels_out_per_group = channels_out / groups;
+
+        let mut group_outputs = Vec::with_capacity(groups);
+        for g in 0..groups {
+            let input_group = padded.index([
+                0..batch_size,
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..padded.shape().dims[2],
+                0..padded.shape().dims[3],
+            ]);
+            let weight_group = weight
+                .index([
+                    g * channels_out_per_group..(g + 1) * channels_out_per_group,
+                    0..channels_in_per_group,
+                    0..kernel_h,
+                    0..kernel_w,
+                ])
+                .reshape(Shape::new([
+                    channels_out_per_group,
+                    channels_in_per_group * kernel_h * kernel_w,
+                ]));
+
+            let columns = input_group.im2col2d(
+                [kernel_h, kernel_w],
+                options.stride,
+                options.dilation,
+                [height_out, width_out],
+            );
+            group_outputs.push(matmul_columns2d(
+                &weight_group,
+                &columns,
+                batch_size,
+                channels_out_per_group,
+                [height_out, width_out],
+            ));
+        }
+
+        let mut output = Tensor::cat(group_outputs, 1);
+
+        if let Some(bias) = bias {
+            output = output.add(&broadcast_bias2d(
+                bias,
+                batch_size,
+                channels_out,
+                [height_out, width_out],
+            ));
+        }
+
+        output
+    }
+
+    fn pad2d(&self, padding: [usize; 2]) -> Self {
+        if padding[0] == 0 && padding[1] == 0 {
+            return self.clone();
+        }
+
+        let [batch_size, channels, height, width] = self.shape().dims;
+        let padded = zeros_on(
+            Shape::new([
+                batch_size,
+                channels,
+                height + 2 * padding[0],
+                width + 2 * padding[1],
+            ]),
+            self.value.device(),
+        );
+
+        padded.index_assign(
+            [
+                0..batch_size,
+                0..channels,
+                padding[0]..padding[0] + height,
+                padding[1]..padding[1] + width,
+            ],
+            self,
+        )
+    }
+
+    fn im2col2d(
+        &self,
+        kernel: [usize; 2],
+        stride: [usize; 2],
+        dilation: [usize; 2],
+        out: [usize; 2],
+    ) -> Tensor<B, 3> {
+        let [batch_size, channels, _, _] = self.shape().dims;
+        let mut patches = Vec::with_capacity(kernel[0] * kernel[1]);
+
+        for kh in 0..kernel[0] {
+            for kw in 0..kernel[1] {
+                let mut columns = Vec::with_capacity(out[0] * out[1]);
+                for oh in 0..out[0] {
+                    for ow in 0..out[1] {
+                        let h = oh * stride[0] + kh * dilation[0];
+                        let w = ow * stride[1] + kw * dilation[1];
+                        columns.push(
+                            self.index([0..batch_size, 0..channels, h..h + 1, w..w + 1])
+                                .reshape(Shape::new([batch_size, channels, 1])),
+                        );
+                    }
+                }
+                let column = Tensor::cat(columns, 2);
+                patches.push(column.reshape(Shape::new([
+                    batch_size,
+                    channels,
+                    1,
+                    out[0] * out[1],
+                ])));
+            }
+        }
+
+        Tensor::cat(patches, 2).reshape(Shape::new([
+            batch_size,
+            channels * kernel[0] * kernel[1],
+            out[0] * out[1],
+        ]))
+    }
+}
+
+impl<B: Backend> Tensor<B, 5> {
+    /// Applies a 3D convolution.
+    ///
+    /// * `x` - shape `[batch_size, channels_in, depth, height, width]`.
+    /// * `weight` - shape `[channels_out, channels_in / groups, kernel_d, kernel_h, kernel_w]`.
+    /// * `bias` - optional shape `[channels_out]`.
+    pub fn conv3d(
+        &self,
+        weight: &Self,
+        bias: Option<&Tensor<B, 1>>,
+        options: ConvOptions<3>,
+    ) -> Self {
+        let [batch_size, channels_in, depth, height, width] = self.shape().dims;
+        let [channels_out, channels_in_per_group, kernel_d, kernel_h, kernel_w] =
+            weight.shape().dims;
+        let groups = options.groups;
+
+        assert_eq!(
+            channels_in,
+            channels_in_per_group * groups,
+            "conv3d: input channels must equal weight's channels_in_per_group * groups"
+        );
+        assert_eq!(
+            channels_out % groups,
+            0,
+            "conv3d: weight's channels_out must be evenly divisible by groups"
+        );
+
+        let padded = self.pad3d(options.padding);
+        let depth_out = conv_output_size(
+            depth,
+            kernel_d,
+            options.stride[0],
+            options.padding[0],
+            options.dilation[0],
+        );
+        let height_out = conv_output_size(
+            height,
+            kernel_h,
+            options.stride[1],
+            options.padding[1],
+            options.dilation[1],
+        );
+        let width_out = conv_output_size(
+            width,
+            kernel_w,
+            options.stride[2],
+            options.padding[2],
+            options.dilation[2],
+        );
+        let channels_out_per_group = channels_out / groups;
+
+        let mut group_outputs = Vec::with_capacity(groups);
+        for g in 0..groups {
+            let input_group = padded.index([
+                0..batch_size,
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..padded.shape().dims[2],
+                0..padded.shape().dims[3],
+                0..padded.shape().dims[4],
+            ]);
+            let weight_group = weight
+                .index([
+                    g * channels_out_per_group..(g + 1) * channels_out_per_group,
+                    0..channels_in_per_group,
+                    0..kernel_d,
+                    0..kernel_h,
+                    0..kernel_w,
+                ])
+                .reshape(Shape::new([
+                    channels_out_per_group,
+                    channels_in_per_group * kernel_d * kernel_h * kernel_w,
+                ]));
+
+            let columns = input_group.im2col3d(
+                [kernel_d, kernel_h, kernel_w],
+                options.stride,
+                options.dilation,
+                [depth_out, height_out, width_out],
+            );
+            group_outputs.push(matmul_columns3d(
+                &weight_group,
+                &columns,
+                batch_size,
+                channels_out_per_group,
+                [depth_out, height_out, width_out],
+            ));
+        }
+
+        let mut output = Tensor::cat(group_outputs, 1);
+
+        if let Some(bias) = bias {
+            output = output.add(&broadcast_bias3d(
+                bias,
+                batch_size,
+                channels_out,
+                [depth_out, height_out, width_out],
+            ));
+        }
+
+        output
+    }
+
+    fn pad3d(&self, padding: [usize; 3]) -> Self {
+        if padding.iter().all(|p| *p == 0) {
+            return self.clone();
+        }
+
+        let [batch_size, channels, depth, height, width] = self.shape().dims;
+        let padded = zeros_on(
+            Shape::new([
+                batch_size,
+                channels,
+                depth + 2 * padding[0],
+                height + 2 * padding[1],
+                width + 2 * padding[2],
+            ]),
+            self.value.device(),
+        );
+
+        padded.index_assign(
+            [
+                0..batch_size,
+                0..channels,
+                padding[0]..padding[0] + depth,
+                padding[1]..padding[1] + height,
+                padding[2]..padding[2] + width,
+            ],
+            self,
+        )
+    }
+
+    fn im2col3d(
+        &self,
+        kernel: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        out: [usize; 3],
+    ) -> Tensor<B, 3> {
+        let [batch_size, channels, _, _, _] = self.shape().dims;
+        let kernel_volume = kernel[0] * kernel[1] * kernel[2];
+        let out_volume = out[0] * out[1] * out[2];
+        let mut patches = Vec::with_capacity(kernel_volume);
+
+        for kd in 0..kernel[0] {
+            for kh in 0..kernel[1] {
+                for kw in 0..kernel[2] {
+                    let mut columns = Vec::with_capacity(out_volume);
+                    for od in 0..out[0] {
+                        for oh in 0..out[1] {
+                            for ow in 0..out[2] {
+                                let d = od * stride[0] + kd * dilation[0];
+                                let h = oh * stride[1] + kh * dilation[1];
+                                let w = ow * stride[2] + kw * dilation[2];
+                                columns.push(
+                                    self.index([
+                                        0..batch_size,
+                                        0..channels,
+                                        d..d + 1,
+                                        h..h + 1,
+                                        w..w + 1,
+                                    ])
+                                    .reshape(Shape::new([batch_size, channels, 1])),
+                                );
+                            }
+                        }
+                    }
+                    let column = Tensor::cat(columns, 2);
+                    patches.push(column.reshape(Shape::new([batch_size, channels, 1, out_volume])));
+                }
+            }
+        }
+
+        Tensor::cat(patches, 2).reshape(Shape::new([
+            batch_size,
+            channels * kernel_volume,
+            out_volume,
+        ]))
+    }
+}
+
+/// Runs the per-batch `matmul` of `weight` (`[channels_out, k]`) against `columns`
+/// (`[batch_size, k, out_volume]`) and reshapes the result to `[batch_size, channels_out, out]`.
+fn matmul_columns1d<B: Backend>(
+    weight: &Tensor<B, 2>,
+    columns: &Tensor<B, 3>,
+    batch_size: usize,
+    channels_out: usize,
+    out: [usize; 1],
+) -> Tensor<B, 3> {
+    let mut batches = Vec::with_capacity(batch_size);
+    for b in 0..batch_size {
+        let out_b = matmul_one_batch(weight, columns, b, out[0]);
+        batches.push(out_b.reshape(Shape::new([1, channels_out, out[0]])));
+    }
+    Tensor::cat(batches, 0)
+}
+
+/// Runs the per-batch `matmul` of `weight` (`[channels_out, k]`) against `columns`
+/// (`[batch_size, k, out_volume]`) and reshapes the result to
+/// `[batch_size, channels_out, out_h, out_w]`.
+fn matmul_columns2d<B: Backend>(
+    weight: &Tensor<B, 2>,
+    columns: &Tensor<B, 3>,
+    batch_size: usize,
+    channels_out: usize,
+    out: [usize; 2],
+) -> Tensor<B, 4> {
+    let out_volume = out[0] * out[1];
+    let mut batches = Vec::with_capacity(batch_size);
+    for b in 0..batch_size {
+        let out_b = matmul_one_batch(weight, columns, b, out_volume);
+        batches.push(out_b.reshape(Shape::new([1, channels_out, out[0], out[1]])));
+    }
+    Tensor::cat(batches, 0)
+}
+
+/// Runs the per-batch `matmul` of `weight` (`[channels_out, k]`) against `columns`
+/// (`[batch_size, k, out_volume]`) and reshapes the result to
+/// `[batch_size, channels_out, out_d, out_h, out_w]`.
+fn matmul_columns3d<B: Backend>(
+    weight: &Tensor<B, 2>,
+    columns: &Tensor<B, 3>,
+    batch_size: usize,
+    channels_out: usize,
+    out: [usize; 3],
+) -> Tensor<B, 5> {
+    let out_volume = out[0] * out[1] * out[2];
+    let mut batches = Vec::with_capacity(batch_size);
+    for b in 0..batch_size {
+        let out_b = matmul_one_batch(weight, columns, b, out_volume);
+        batches.push(out_b.reshape(Shape::new([1, channels_out, out[0], out[1], out[2]])));
+    }
+    Tensor::cat(batches, 0)
+}
+
+fn matmul_one_batch<B: Backend>(
+    weight: &Tensor<B, 2>,
+    columns: &Tensor<B, 3>,
+    batch_index: usize,
+    out_volume: usize,
+) -> Tensor<B, 2> {
+    let k = columns.shape().dims[1];
+    let cols_b = columns
+        .index([batch_index..batch_index + 1, 0..k, 0..out_volume])
+        .reshape(Shape::new([k, out_volume]));
+
+    weight.matmul(&cols_b)
+}
+
+/// Builds a `[batch_size, channels, out]` tensor by broadcasting the per-channel `bias`.
+fn broadcast_bias1d<B: Backend>(
+    bias: &Tensor<B, 1>,
+    batch_size: usize,
+    channels: usize,
+    out: [usize; 1],
+) -> Tensor<B, 3> {
+    assert_eq!(
+        bias.shape().dims[0],
+        channels,
+        "broadcast_bias1d: bias length must equal channels_out"
+    );
+
+    let bias_data = bias.to_data();
+    let shape = Shape::new([batch_size, 1, out[0]]);
+
+    let channel_tensors: Vec<_> = (0..channels)
+        .map(|c| ones_on::<B, 3>(shape, bias.value.device()).mul_scalar(&bias_data.value[c]))
+        .collect();
+
+    Tensor::cat(channel_tensors, 1)
+}
+
+/// Builds a `[batch_size, channels, out_h, out_w]` tensor by broadcasting the per-channel `bias`.
+fn broadcast_bias2d<B: Backend>(
+    bias: &Tensor<B, 1>,
+    batch_size: usize,
+    channels: usize,
+    out: [usize; 2],
+) -> Tensor<B, 4> {
+    assert_eq!(
+        bias.shape().dims[0],
+        channels,
+        "broadcast_bias2d: bias length must equal channels_out"
+    );
+
+    let bias_data = bias.to_data();
+    let shape = Shape::new([batch_size, 1, out[0], out[1]]);
+
+    let channel_tensors: Vec<_> = (0..channels)
+        .map(|c| ones_on::<B, 4>(shape, bias.value.device()).mul_scalar(&bias_data.value[c]))
+        .collect();
+
+    Tensor::cat(channel_tensors, 1)
+}
+
+/// Builds a `[batch_size, channels, out_d, out_h, out_w]` tensor by broadcasting the
+/// per-channel `bias`.
+fn broadcast_bias3d<B: Backend>(
+    bias: &Tensor<B, 1>,
+    batch_size: usize,
+    channels: usize,
+    out: [usize; 3],
+) -> Tensor<B, 5> {
+    assert_eq!(
+        bias.shape().dims[0],
+        channels,
+        "broadcast_bias3d: bias length must equal channels_out"
+    );
+
+    let bias_data = bias.to_data();
+    let shape = Shape::new([batch_size, 1, out[0], out[1], out[2]]);
+
+    let channel_tensors: Vec<_> = (0..channels)
+        .map(|c| ones_on::<B, 5>(shape, bias.value.device()).mul_scalar(&bias_data.value[c]))
+        .collect();
+
+    Tensor::cat(channel_tensors, 1)
+}
+
+/// Configuration for a transposed convolution (deconvolution) operation over `N` spatial
+/// dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConvTransposeOptions<const N: usize> {
+    /// Stride of the kernel along each spatial dimension.
+    pub stride: [usize; N],
+    /// Zero-padding removed from both sides of each spatial dimension.
+    pub padding: [usize; N],
+    /// Additional size added to one side of each output spatial dimension, resolving the size
+    /// ambiguity introduced by `stride`. Must be smaller than both `stride` and `dilation`.
+    pub output_padding: [usize; N],
+    /// Spacing between kernel elements along each spatial dimension.
+    pub dilation: [usize; N],
+    /// Number of blocked connections from input channels to output channels.
+    pub groups: usize,
+}
+
+impl<const N: usize> ConvTransposeOptions<N> {
+    /// Creates a new set of transposed convolution options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any `output_padding` entry is not smaller than the corresponding `stride` and
+    /// `dilation` entry.
+    pub fn new(
+        stride: [usize; N],
+        padding: [usize; N],
+        output_padding: [usize; N],
+        dilation: [usize; N],
+        groups: usize,
+    ) -> Self {
+        for i in 0..N {
+            assert!(
+                output_padding[i] < stride[i] && output_padding[i] < dilation[i],
+                "output_padding must be smaller than stride and dilation"
+            );
+        }
+
+        Self {
+            stride,
+            padding,
+            output_padding,
+            dilation,
+            groups,
+        }
+    }
+}
+
+/// Computes the output size of a single spatial dimension of a transposed convolution.
+fn conv_transpose_output_size(
+    size: usize,
+    kernel: usize,
+    stride: usize,
+    padding: usize,
+    output_padding: usize,
+    dilation: usize,
+) -> usize {
+    (size - 1) * stride - 2 * padding + dilation * (kernel - 1) + output_padding + 1
+}
+
+impl<B: Backend> Tensor<B, 3> {
+    /// Applies a 1D transposed convolution (deconvolution).
+    ///
+    /// * `x` - shape `[batch_size, channels_in, length]`.
+    /// * `weight` - shape `[channels_in, channels_out / groups, kernel_size]`.
+    /// * `bias` - optional shape `[channels_out]`.
+    ///
+    /// The default implementation scatter-accumulates each input element, scaled by the
+    /// kernel, into the (strided) output buffer -- the same shape the gradient of `conv1d`
+    /// with respect to its input would take.
+    pub fn conv_transpose1d(
+        &self,
+        weight: &Self,
+        bias: Option<&Tensor<B, 1>>,
+        options: ConvTransposeOptions<1>,
+    ) -> Self {
+        let [batch_size, channels_in, length] = self.shape().dims;
+        let [channels_in_weight, channels_out_per_group, kernel_size] = weight.shape().dims;
+        let groups = options.groups;
+
+        assert_eq!(
+            channels_in, channels_in_weight,
+            "conv_transpose1d: input channels must equal weight's channels_in"
+        );
+
+        let channels_in_per_group = channels_in / groups;
+        let channels_out = channels_out_per_group * groups;
+        let length_out = conv_transpose_output_size(
+            length,
+            kernel_size,
+            options.stride[0],
+            options.padding[0],
+            options.output_padding[0],
+            options.dilation[0],
+        );
+
+        let mut group_outputs = Vec::with_capacity(groups);
+        for g in 0..groups {
+            let input_group = self.index([
+                0..batch_size,
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..length,
+            ]);
+            let weight_group = weight.index([
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..channels_out_per_group,
+                0..kernel_size,
+            ]);
+
+            let mut output_group = zeros_on(
+                Shape::new([batch_size, channels_out_per_group, length_out]),
+                self.value.device(),
+            );
+
+            for i in 0..length {
+                for k in 0..kernel_size {
+                    let o = i as isize * options.stride[0] as isize
+                        + k as isize * options.dilation[0] as isize
+                        - options.padding[0] as isize;
+                    if o < 0 || o as usize >= length_out {
+                        continue;
+                    }
+                    let o = o as usize;
+
+                    let contribution = transpose_contribution(
+                        &weight_group.index([0..channels_in_per_group, 0..channels_out_per_group, k..k + 1]),
+                        &input_group.index([0..batch_size, 0..channels_in_per_group, i..i + 1]),
+                        batch_size,
+                        channels_in_per_group,
+                        channels_out_per_group,
+                    )
+                    .reshape(Shape::new([batch_size, channels_out_per_group, 1]));
+
+                    let region = [0..batch_size, 0..channels_out_per_group, o..o + 1];
+                    let accumulated = output_group.index(region.clone()).add(&contribution);
+                    output_group = output_group.index_assign(region, &accumulated);
+                }
+            }
+
+            group_outputs.push(output_group);
+        }
+
+        let mut output = Tensor::cat(group_outputs, 1);
+
+        if let Some(bias) = bias {
+            output = output.add(&broadcast_bias1d(bias, batch_size, channels_out, [length_out]));
+        }
+
+        output
+    }
+}
+
+impl<B: Backend> Tensor<B, 4> {
+    /// Applies a 2D transposed convolution (deconvolution).
+    ///
+    /// * `x` - shape `[batch_size, channels_in, height, width]`.
+    /// * `weight` - shape `[channels_in, channels_out / groups, kernel_h, kernel_w]`.
+    /// * `bias` - optional shape `[channels_out]`.
+    pub fn conv_transpose2d(
+        &self,
+        weight: &Self,
+        bias: Option<&Tensor<B, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> Self {
+        let [batch_size, channels_in, height, width] = self.shape().dims;
+        let [channels_in_weight, channels_out_per_group, kernel_h, kernel_w] = weight.shape().dims;
+        let groups = options.groups;
+
+        assert_eq!(
+            channels_in, channels_in_weight,
+            "conv_transpose2d: input channels must equal weight's channels_in"
+        );
+
+        let channels_in_per_group = channels_in / groups;
+        let channels_out = channels_out_per_group * groups;
+        let height_out = conv_transpose_output_size(
+            height,
+            kernel_h,
+            options.stride[0],
+            options.padding[0],
+            options.output_padding[0],
+            options.dilation[0],
+        );
+        let width_out = conv_transpose_output_size(
+            width,
+            kernel_w,
+            options.stride[1],
+            options.padding[1],
+            options.output_padding[1],
+            options.dilation[1],
+        );
+
+        let mut group_outputs = Vec::with_capacity(groups);
+        for g in 0..groups {
+            let input_group = self.index([
+                0..batch_size,
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..height,
+                0..width,
+            ]);
+            let weight_group = weight.index([
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..channels_out_per_group,
+                0..kernel_h,
+                0..kernel_w,
+            ]);
+
+            let mut output_group = zeros_on(
+                Shape::new([batch_size, channels_out_per_group, height_out, width_out]),
+                self.value.device(),
+            );
+
+            for ih in 0..height {
+                for iw in 0..width {
+                    for kh in 0..kernel_h {
+                        let oh = ih as isize * options.stride[0] as isize
+                            + kh as isize * options.dilation[0] as isize
+                            - options.padding[0] as isize;
+                        if oh < 0 || oh as usize >= height_out {
+                            continue;
+                        }
+                        let oh = oh as usize;
+
+                        for kw in 0..kernel_w {
+                            let ow = iw as isize * options.stride[1] as isize
+                                + kw as isize * options.dilation[1] as isize
+                                - options.padding[1] as isize;
+                            if ow < 0 || ow as usize >= width_out {
+                                continue;
+                            }
+                            let ow = ow as usize;
+
+                            let contribution = transpose_contribution(
+                                &weight_group.index([
+                                    0..channels_in_per_group,
+                                    0..channels_out_per_group,
+                                    kh..kh + 1,
+                                    kw..kw + 1,
+                                ]),
+                                &input_group.index([
+                                    0..batch_size,
+                                    0..channels_in_per_group,
+                                    ih..ih + 1,
+                                    iw..iw + 1,
+                                ]),
+                                batch_size,
+                                channels_in_per_group,
+                                channels_out_per_group,
+                            )
+                            .reshape(Shape::new([batch_size, channels_out_per_group, 1, 1]));
+
+                            let region = [
+                                0..batch_size,
+                                0..channels_out_per_group,
+                                oh..oh + 1,
+                                ow..ow + 1,
+                            ];
+                            let accumulated = output_group.index(region.clone()).add(&contribution);
+                            output_group = output_group.index_assign(region, &accumulated);
+                        }
+                    }
+                }
+            }
+
+            group_outputs.push(output_group);
+        }
+
+        let mut output = Tensor::cat(group_outputs, 1);
+
+        if let Some(bias) = bias {
+            output = output.add(&broadcast_bias2d(bias, batch_size, channels_out, [height_out, width_out]));
+        }
+
+        output
+    }
+}
+
+impl<B: Backend> Tensor<B, 5> {
+    /// Applies a 3D transposed convolution (deconvolution).
+    ///
+    /// * `x` - shape `[batch_size, channels_in, depth, height, width]`.
+    /// * `weight` - shape `[channels_in, channels_out / groups, kernel_d, kernel_h, kernel_w]`.
+    /// * `bias` - optional shape `[channels_out]`.
+    pub fn conv_transpose3d(
+        &self,
+        weight: &Self,
+        bias: Option<&Tensor<B, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> Self {
+        let [batch_size, channels_in, depth, height, width] = self.shape().dims;
+        let [channels_in_weight, channels_out_per_group, kernel_d, kernel_h, kernel_w] =
+            weight.shape().dims;
+        let groups = options.groups;
+
+        assert_eq!(
+            channels_in, channels_in_weight,
+            "conv_transpose3d: input channels must equal weight's channels_in"
+        );
+
+        let channels_in_per_group = channels_in / groups;
+        let channels_out = channels_out_per_group * groups;
+        let depth_out = conv_transpose_output_size(
+            depth,
+            kernel_d,
+            options.stride[0],
+            options.padding[0],
+            options.output_padding[0],
+            options.dilation[0],
+        );
+        let height_out = conv_transpose_output_size(
+            height,
+            kernel_h,
+            options.stride[1],
+            options.padding[1],
+            options.output_padding[1],
+            options.dilation[1],
+        );
+        let width_out = conv_transpose_output_size(
+            width,
+            kernel_w,
+            options.stride[2],
+            options.padding[2],
+            options.output_padding[2],
+            options.dilation[2],
+        );
+
+        let mut group_outputs = Vec::with_capacity(groups);
+        for g in 0..groups {
+            let input_group = self.index([
+                0..batch_size,
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..depth,
+                0..height,
+                0..width,
+            ]);
+            let weight_group = weight.index([
+                g * channels_in_per_group..(g + 1) * channels_in_per_group,
+                0..channels_out_per_group,
+                0..kernel_d,
+                0..kernel_h,
+                0..kernel_w,
+            ]);
+
+            let mut output_group = zeros_on(
+                Shape::new([batch_size, channels_out_per_group, depth_out, height_out, width_out]),
+                self.value.device(),
+            );
+
+            for id in 0..depth {
+                for ih in 0..height {
+                    for iw in 0..width {
+                        for kd in 0..kernel_d {
+                            let od = id as isize * options.stride[0] as isize
+                                + kd as isize * options.dilation[0] as isize
+                                - options.padding[0] as isize;
+                            if od < 0 || od as usize >= depth_out {
+                                continue;
+                            }
+                            let od = od as usize;
+
+                            for kh in 0..kernel_h {
+                                let oh = ih as isize * options.stride[1] as isize
+                                    + kh as isize * options.dilation[1] as isize
+                                    - options.padding[1] as isize;
+                                if oh < 0 || oh as usize >= height_out {
+                                    continue;
+                                }
+                                let oh = oh as usize;
+
+                                for kw in 0..kernel_w {
+                                    let ow = iw as isize * options.stride[2] as isize
+                                        + kw as isize * options.dilation[2] as isize
+                                        - options.padding[2] as isize;
+                                    if ow < 0 || ow as usize >= width_out {
+                                        continue;
+                                    }
+                                    let ow = ow as usize;
+
+                                    let contribution = transpose_contribution(
+                                        &weight_group.index([
+                                            0..channels_in_per_group,
+                                            0..channels_out_per_group,
+                                            kd..kd + 1,
+                                            kh..kh + 1,
+                                            kw..kw + 1,
+                                        ]),
+                                        &input_group.index([
+                                            0..batch_size,
+                                            0..channels_in_per_group,
+                                            id..id + 1,
+                                            ih..ih + 1,
+                                            iw..iw + 1,
+                                        ]),
+                                        batch_size,
+                                        channels_in_per_group,
+                                        channels_out_per_group,
+                                    )
+                                    .reshape(Shape::new([batch_size, channels_out_per_group, 1, 1, 1]));
+
+                                    let region = [
+                                        0..batch_size,
+                                        0..channels_out_per_group,
+                                        od..od + 1,
+                                        oh..oh + 1,
+                                        ow..ow + 1,
+                                    ];
+                                    let accumulated =
+                                        output_group.index(region.clone()).add(&contribution);
+                                    output_group = output_group.index_assign(region, &accumulated);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            group_outputs.push(output_group);
+        }
+
+        let mut output = Tensor::cat(group_outputs, 1);
+
+        if let Some(bias) = bias {
+            output = output.add(&broadcast_bias3d(
+                bias,
+                batch_size,
+                channels_out,
+                [depth_out, height_out, width_out],
+            ));
+        }
+
+        output
+    }
+}
+
+/// Computes, for a single kernel offset, the contribution `input @ weight` of shape
+/// `[batch_size, channels_out_per_group]`, where `weight` is `[channels_in_per_group,
+/// channels_out_per_group]` and `input` is `[batch_size, channels_in_per_group]` once their
+/// singleton kernel/spatial dimensions are squeezed away.
+fn transpose_contribution<B: Backend, const D: usize>(
+    weight_slice: &Tensor<B, D>,
+    input_slice: &Tensor<B, D>,
+    batch_size: usize,
+    channels_in_per_group: usize,
+    channels_out_per_group: usize,
+) -> Tensor<B, 2> {
+    let weight = weight_slice
+        .reshape(Shape::new([channels_in_per_group, channels_out_per_group]))
+        .transpose();
+    let input = input_slice
+        .reshape(Shape::new([batch_size, channels_in_per_group]))
+        .transpose();
+
+    weight.matmul(&input).transpose()
+}
+
 impl<const D: usize, B> std::ops::Add<Self> for Tensor<B, D>
 where
     B: Backend,
@@ -367,6 +1531,412 @@ where
     }
 }
 
+/// Marker for backends whose tensor primitive holds an integer element type and therefore
+/// supports bitwise and shift operations. Any backend reachable through `B::IntegerBackend`
+/// (e.g. the output of [`Tensor::argmax`]) already satisfies this bound.
+pub trait IntegerBackend: Backend<IntegerBackend = Self> {}
+
+impl<B> IntegerBackend for B where B: Backend<IntegerBackend = B> {}
+
+impl<const D: usize, B> Tensor<B, D>
+where
+    B: IntegerBackend,
+{
+    /// Applies `op` element-wise to `self` and `other`, reading both through `to_data` and
+    /// writing the result back through `from_data_device`.
+    ///
+    /// Bitwise and shift operations have no corresponding `TensorOps` primitive in this crate,
+    /// so unlike the arithmetic ops above, these round-trip through the host buffer -- the same
+    /// primitive [`Tensor::value_at`] relies on -- rather than dispatching to the backend.
+    fn zip_map(&self, other: &Self, op: impl Fn(B::Elem, B::Elem) -> B::Elem) -> Self {
+        let lhs = self.to_data();
+        let rhs = other.to_data();
+        let value = lhs
+            .value
+            .into_iter()
+            .zip(rhs.value)
+            .map(|(a, b)| op(a, b))
+            .collect();
+
+        Tensor::from_data_device(Data::new(value, lhs.shape), self.value.device())
+    }
+
+    /// Applies `op` element-wise to `self`. See [`Tensor::zip_map`].
+    fn map(&self, op: impl Fn(B::Elem) -> B::Elem) -> Self {
+        let data = self.to_data();
+        let value = data.value.into_iter().map(op).collect();
+
+        Tensor::from_data_device(Data::new(value, data.shape), self.value.device())
+    }
+
+    pub fn bitand(&self, other: &Self) -> Self
+    where
+        B::Elem: std::ops::BitAnd<Output = B::Elem>,
+    {
+        self.zip_map(other, |a, b| a & b)
+    }
+
+    pub fn bitand_scalar(&self, other: &B::Elem) -> Self
+    where
+        B::Elem: std::ops::BitAnd<Output = B::Elem> + Copy,
+    {
+        let other = *other;
+        self.map(|a| a & other)
+    }
+
+    pub fn bitor(&self, other: &Self) -> Self
+    where
+        B::Elem: std::ops::BitOr<Output = B::Elem>,
+    {
+        self.zip_map(other, |a, b| a | b)
+    }
+
+    pub fn bitor_scalar(&self, other: &B::Elem) -> Self
+    where
+        B::Elem: std::ops::BitOr<Output = B::Elem> + Copy,
+    {
+        let other = *other;
+        self.map(|a| a | other)
+    }
+
+    pub fn bitxor(&self, other: &Self) -> Self
+    where
+        B::Elem: std::ops::BitXor<Output = B::Elem>,
+    {
+        self.zip_map(other, |a, b| a ^ b)
+    }
+
+    pub fn bitxor_scalar(&self, other: &B::Elem) -> Self
+    where
+        B::Elem: std::ops::BitXor<Output = B::Elem> + Copy,
+    {
+        let other = *other;
+        self.map(|a| a ^ other)
+    }
+
+    pub fn bitnot(&self) -> Self
+    where
+        B::Elem: std::ops::Not<Output = B::Elem>,
+    {
+        self.map(|a| !a)
+    }
+
+    pub fn shift_left(&self, other: &Self) -> Self
+    where
+        B::Elem: std::ops::Shl<Output = B::Elem>,
+    {
+        self.zip_map(other, |a, b| a << b)
+    }
+
+    pub fn shift_left_scalar(&self, other: &B::Elem) -> Self
+    where
+        B::Elem: std::ops::Shl<Output = B::Elem> + Copy,
+    {
+        let other = *other;
+        self.map(|a| a << other)
+    }
+
+    pub fn shift_right(&self, other: &Self) -> Self
+    where
+        B::Elem: std::ops::Shr<Output = B::Elem>,
+    {
+        self.zip_map(other, |a, b| a >> b)
+    }
+
+    pub fn shift_right_scalar(&self, other: &B::Elem) -> Self
+    where
+        B::Elem: std::ops::Shr<Output = B::Elem> + Copy,
+    {
+        let other = *other;
+        self.map(|a| a >> other)
+    }
+}
+
+impl<const D: usize, B> std::ops::BitAnd<Self> for Tensor<B, D>
+where
+    B: IntegerBackend,
+    B::Elem: std::ops::BitAnd<Output = B::Elem>,
+{
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Tensor::bitand(&self, &other)
+    }
+}
+
+impl<E, const D: usize, B> std::ops::BitAnd<E> for Tensor<B, D>
+where
+    E: Element + std::ops::BitAnd<Output = E> + Copy,
+    B: IntegerBackend<Elem = E>,
+{
+    type Output = Self;
+
+    fn bitand(self, other: E) -> Self {
+        Tensor::bitand_scalar(&self, &other)
+    }
+}
+
+impl<const D: usize, B> std::ops::BitOr<Self> for Tensor<B, D>
+where
+    B: IntegerBackend,
+    B::Elem: std::ops::BitOr<Output = B::Elem>,
+{
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Tensor::bitor(&self, &other)
+    }
+}
+
+impl<E, const D: usize, B> std::ops::BitOr<E> for Tensor<B, D>
+where
+    E: Element + std::ops::BitOr<Output = E> + Copy,
+    B: IntegerBackend<Elem = E>,
+{
+    type Output = Self;
+
+    fn bitor(self, other: E) -> Self {
+        Tensor::bitor_scalar(&self, &other)
+    }
+}
+
+impl<const D: usize, B> std::ops::BitXor<Self> for Tensor<B, D>
+where
+    B: IntegerBackend,
+    B::Elem: std::ops::BitXor<Output = B::Elem>,
+{
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        Tensor::bitxor(&self, &other)
+    }
+}
+
+impl<E, const D: usize, B> std::ops::BitXor<E> for Tensor<B, D>
+where
+    E: Element + std::ops::BitXor<Output = E> + Copy,
+    B: IntegerBackend<Elem = E>,
+{
+    type Output = Self;
+
+    fn bitxor(self, other: E) -> Self {
+        Tensor::bitxor_scalar(&self, &other)
+    }
+}
+
+impl<const D: usize, B> std::ops::Not for Tensor<B, D>
+where
+    B: IntegerBackend,
+    B::Elem: std::ops::Not<Output = B::Elem>,
+{
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Tensor::bitnot(&self)
+    }
+}
+
+impl<const D: usize, B> std::ops::Shl<Self> for Tensor<B, D>
+where
+    B: IntegerBackend,
+    B::Elem: std::ops::Shl<Output = B::Elem>,
+{
+    type Output = Self;
+
+    fn shl(self, other: Self) -> Self {
+        Tensor::shift_left(&self, &other)
+    }
+}
+
+impl<E, const D: usize, B> std::ops::Shl<E> for Tensor<B, D>
+where
+    E: Element + std::ops::Shl<Output = E> + Copy,
+    B: IntegerBackend<Elem = E>,
+{
+    type Output = Self;
+
+    fn shl(self, other: E) -> Self {
+        Tensor::shift_left_scalar(&self, &other)
+    }
+}
+
+impl<const D: usize, B> std::ops::Shr<Self> for Tensor<B, D>
+where
+    B: IntegerBackend,
+    B::Elem: std::ops::Shr<Output = B::Elem>,
+{
+    type Output = Self;
+
+    fn shr(self, other: Self) -> Self {
+        Tensor::shift_right(&self, &other)
+    }
+}
+
+impl<E, const D: usize, B> std::ops::Shr<E> for Tensor<B, D>
+where
+    E: Element + std::ops::Shr<Output = E> + Copy,
+    B: IntegerBackend<Elem = E>,
+{
+    type Output = Self;
+
+    fn shr(self, other: E) -> Self {
+        Tensor::shift_right_scalar(&self, &other)
+    }
+}
+
+/// For each of the `output_size` cells along one dimension, returns the `[start, end)` window
+/// of the input that feeds it: `start = floor(o*in_size/out_size)`, `end =
+/// ceil((o+1)*in_size/out_size)`. Window sizes vary across the output, which is what makes
+/// adaptive pooling size-agnostic.
+fn adaptive_pool_windows(in_size: usize, out_size: usize) -> Vec<(usize, usize)> {
+    (0..out_size)
+        .map(|o| {
+            let start = o * in_size / out_size;
+            let end = ((o + 1) * in_size).div_ceil(out_size);
+            (start, end)
+        })
+        .collect()
+}
+
+impl<const D: usize, B: Backend> Tensor<B, D> {
+    /// Reduces `dim` to size 1 by taking the maximum element along it.
+    ///
+    /// There is no backend-dispatched max-reduction primitive in this crate (unlike
+    /// `sum_dim`/`mean_dim` above), so this reads through `to_data` and writes the result back
+    /// through `from_data_device` -- the same host round-trip used for the bitwise/shift ops.
+    fn max_dim(&self, dim: usize) -> Self
+    where
+        B::Elem: PartialOrd,
+    {
+        let data = self.to_data();
+        let dims = data.shape.dims;
+
+        let mut strides = [1; D];
+        for i in (0..D - 1).rev() {
+            strides[i] = strides[i + 1] * dims[i + 1];
+        }
+
+        let mut out_dims = dims;
+        out_dims[dim] = 1;
+        let mut out_strides = [1; D];
+        for i in (0..D - 1).rev() {
+            out_strides[i] = out_strides[i + 1] * out_dims[i + 1];
+        }
+
+        let out_len: usize = out_dims.iter().product();
+        let mut value = Vec::with_capacity(out_len);
+        for out_flat in 0..out_len {
+            let mut coords = [0; D];
+            let mut rem = out_flat;
+            for (i, coord) in coords.iter_mut().enumerate() {
+                *coord = rem / out_strides[i];
+                rem %= out_strides[i];
+            }
+
+            let max = (0..dims[dim])
+                .map(|k| {
+                    let in_flat: usize = (0..D)
+                        .map(|i| if i == dim { k } else { coords[i] } * strides[i])
+                        .sum();
+                    data.value[in_flat]
+                })
+                .reduce(|a, b| if b > a { b } else { a })
+                .unwrap();
+            value.push(max);
+        }
+
+        Tensor::from_data_device(Data::new(value, Shape::new(out_dims)), self.value.device())
+    }
+}
+
+impl<B: Backend> Tensor<B, 4> {
+    /// Applies 2D adaptive average pooling, reducing `[batch_size, channels, height, width]` to
+    /// a fixed `[batch_size, channels, output_size[0], output_size[1]]` regardless of the input
+    /// spatial size.
+    pub fn adaptive_avg_pool2d(&self, output_size: [usize; 2]) -> Self {
+        self.adaptive_pool2d(output_size, |window| window.mean_dim(2).mean_dim(3))
+    }
+
+    /// Applies 2D adaptive max pooling.
+    pub fn adaptive_max_pool2d(&self, output_size: [usize; 2]) -> Self
+    where
+        B::Elem: PartialOrd,
+    {
+        self.adaptive_pool2d(output_size, |window| window.max_dim(2).max_dim(3))
+    }
+
+    fn adaptive_pool2d(&self, output_size: [usize; 2], reduce: impl Fn(Self) -> Self) -> Self {
+        let [batch_size, channels, height, width] = self.shape().dims;
+        let [height_out, width_out] = output_size;
+
+        let height_windows = adaptive_pool_windows(height, height_out);
+        let width_windows = adaptive_pool_windows(width, width_out);
+
+        let mut output = zeros_on(
+            Shape::new([batch_size, channels, height_out, width_out]),
+            self.value.device(),
+        );
+
+        for (oh, &(h0, h1)) in height_windows.iter().enumerate() {
+            for (ow, &(w0, w1)) in width_windows.iter().enumerate() {
+                let window = self.index([0..batch_size, 0..channels, h0..h1, w0..w1]);
+                let pooled = reduce(window);
+
+                output = output.index_assign([0..batch_size, 0..channels, oh..oh + 1, ow..ow + 1], &pooled);
+            }
+        }
+
+        output
+    }
+}
+
+impl<B: Backend> Tensor<B, 5> {
+    /// Applies 3D adaptive average pooling, reducing `[batch_size, channels, depth, height,
+    /// width]` to a fixed `[batch_size, channels, output_size[0], output_size[1],
+    /// output_size[2]]` regardless of the input spatial size.
+    pub fn adaptive_avg_pool3d(&self, output_size: [usize; 3]) -> Self {
+        self.adaptive_pool3d(output_size, |window| window.mean_dim(2).mean_dim(3).mean_dim(4))
+    }
+
+    /// Applies 3D adaptive max pooling.
+    pub fn adaptive_max_pool3d(&self, output_size: [usize; 3]) -> Self
+    where
+        B::Elem: PartialOrd,
+    {
+        self.adaptive_pool3d(output_size, |window| window.max_dim(2).max_dim(3).max_dim(4))
+    }
+
+    fn adaptive_pool3d(&self, output_size: [usize; 3], reduce: impl Fn(Self) -> Self) -> Self {
+        let [batch_size, channels, depth, height, width] = self.shape().dims;
+        let [depth_out, height_out, width_out] = output_size;
+
+        let depth_windows = adaptive_pool_windows(depth, depth_out);
+        let height_windows = adaptive_pool_windows(height, height_out);
+        let width_windows = adaptive_pool_windows(width, width_out);
+
+        let mut output = zeros_on(
+            Shape::new([batch_size, channels, depth_out, height_out, width_out]),
+            self.value.device(),
+        );
+
+        for (od, &(d0, d1)) in depth_windows.iter().enumerate() {
+            for (oh, &(h0, h1)) in height_windows.iter().enumerate() {
+                for (ow, &(w0, w1)) in width_windows.iter().enumerate() {
+                    let window = self.index([0..batch_size, 0..channels, d0..d1, h0..h1, w0..w1]);
+                    let pooled = reduce(window);
+
+                    output = output.index_assign(
+                        [0..batch_size, 0..channels, od..od + 1, oh..oh + 1, ow..ow + 1],
+                        &pooled,
+                    );
+                }
+            }
+        }
+
+        output
+    }
+}
+
 impl<const D: usize, B: ADBackend> Tensor<B, D> {
     pub fn backward(&self) -> Gradients {
         B::backward::<D>(&self.value)
@@ -391,4 +1961,246 @@ impl<const D: usize, B: ADBackend> Tensor<B, D> {
     pub fn detach(&self) -> Self {
         Self::from_inner(self.inner())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestBackend = burn_ndarray::NdArrayBackend<f32>;
+    type TestBackendInt = burn_ndarray::NdArrayBackend<i64>;
+
+    #[test]
+    fn conv1d_matches_hand_computed_output() {
+        let x: Tensor<TestBackend, 3> =
+            Tensor::from_data(Data::new(vec![1.0, 2.0, 3.0, 4.0], Shape::new([1, 1, 4])));
+        let weight: Tensor<TestBackend, 3> =
+            Tensor::from_data(Data::new(vec![1.0, 1.0], Shape::new([1, 1, 2])));
+
+        let output = x.conv1d(&weight, None, ConvOptions::new([1], [0], [1], 1));
+
+        assert_eq!(output.shape().dims, [1, 1, 3]);
+        assert_eq!(output.into_data().value, vec![3.0, 5.0, 7.0]);
+    }
+
+    #[test]
+    fn conv2d_matches_hand_computed_output() {
+        let x: Tensor<TestBackend, 4> = Tensor::from_data(Data::new(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+            Shape::new([1, 1, 3, 3]),
+        ));
+        let weight: Tensor<TestBackend, 4> = Tensor::from_data(Data::new(
+            vec![1.0, 1.0, 1.0, 1.0],
+            Shape::new([1, 1, 2, 2]),
+        ));
+
+        let output = x.conv2d(&weight, None, ConvOptions::new([1, 1], [0, 0], [1, 1], 1));
+
+        assert_eq!(output.shape().dims, [1, 1, 2, 2]);
+        assert_eq!(output.into_data().value, vec![12.0, 16.0, 24.0, 28.0]);
+    }
+
+    #[test]
+    fn conv3d_matches_hand_computed_output() {
+        let x: Tensor<TestBackend, 5> = Tensor::from_data(Data::new(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            Shape::new([1, 1, 2, 2, 2]),
+        ));
+        let weight: Tensor<TestBackend, 5> = Tensor::from_data(Data::new(
+            vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            Shape::new([1, 1, 2, 2, 2]),
+        ));
+
+        let output = x.conv3d(&weight, None, ConvOptions::new([1, 1, 1], [0, 0, 0], [1, 1, 1], 1));
+
+        assert_eq!(output.shape().dims, [1, 1, 1, 1, 1]);
+        assert_eq!(output.into_data().value, vec![36.0]);
+    }
+
+    #[test]
+    fn conv_transpose1d_matches_hand_computed_output() {
+        let x: Tensor<TestBackend, 3> =
+            Tensor::from_data(Data::new(vec![1.0, 2.0], Shape::new([1, 1, 2])));
+        let weight: Tensor<TestBackend, 3> =
+            Tensor::from_data(Data::new(vec![1.0, 1.0], Shape::new([1, 1, 2])));
+
+        let output =
+            x.conv_transpose1d(&weight, None, ConvTransposeOptions::new([2], [0], [0], [1], 1));
+
+        assert_eq!(output.shape().dims, [1, 1, 4]);
+        assert_eq!(output.into_data().value, vec![1.0, 1.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn conv_transpose2d_matches_hand_computed_output() {
+        let x: Tensor<TestBackend, 4> = Tensor::from_data(Data::new(
+            vec![1.0, 2.0, 3.0, 4.0],
+            Shape::new([1, 1, 2, 2]),
+        ));
+        let weight: Tensor<TestBackend, 4> = Tensor::from_data(Data::new(
+            vec![1.0, 1.0, 1.0, 1.0],
+            Shape::new([1, 1, 2, 2]),
+        ));
+
+        let output = x.conv_transpose2d(
+            &weight,
+            None,
+            ConvTransposeOptions::new([1, 1], [0, 0], [0, 0], [1, 1], 1),
+        );
+
+        assert_eq!(output.shape().dims, [1, 1, 3, 3]);
+        assert_eq!(
+            output.into_data().value,
+            vec![1.0, 3.0, 2.0, 4.0, 10.0, 6.0, 3.0, 7.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn conv_transpose3d_matches_hand_computed_output() {
+        let x: Tensor<TestBackend, 5> =
+            Tensor::from_data(Data::new(vec![2.0], Shape::new([1, 1, 1, 1, 1])));
+        let weight: Tensor<TestBackend, 5> = Tensor::from_data(Data::new(
+            vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            Shape::new([1, 1, 2, 2, 2]),
+        ));
+
+        let output = x.conv_transpose3d(
+            &weight,
+            None,
+            ConvTransposeOptions::new([1, 1, 1], [0, 0, 0], [0, 0, 0], [1, 1, 1], 1),
+        );
+
+        assert_eq!(output.shape().dims, [1, 1, 2, 2, 2]);
+        assert_eq!(
+            output.into_data().value,
+            vec![2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0, 2.0]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn conv_transpose_options_rejects_output_padding_not_smaller_than_stride() {
+        ConvTransposeOptions::new([1], [0], [1], [1], 1);
+    }
+
+    #[test]
+    fn into_scalar_returns_the_single_element() {
+        let x: Tensor<TestBackend, 1> = Tensor::from_data(Data::new(vec![5.0], Shape::new([1])));
+        assert_eq!(x.into_scalar(), 5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_scalar_panics_on_non_scalar_shape() {
+        let x: Tensor<TestBackend, 1> =
+            Tensor::from_data(Data::new(vec![1.0, 2.0], Shape::new([2])));
+        x.into_scalar();
+    }
+
+    #[test]
+    fn value_at_reads_the_requested_element() {
+        let x: Tensor<TestBackend, 2> = Tensor::from_data(Data::new(
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+            Shape::new([2, 3]),
+        ));
+        assert_eq!(x.value_at([1, 2]), 5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn value_at_panics_on_out_of_bounds_axis() {
+        let x: Tensor<TestBackend, 2> = Tensor::from_data(Data::new(
+            vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+            Shape::new([2, 3]),
+        ));
+        x.value_at([0, 5]);
+    }
+
+    #[test]
+    fn bitand_matches_hand_computed_output() {
+        let a: Tensor<TestBackendInt, 1> =
+            Tensor::from_data(Data::new(vec![6, 5], Shape::new([2])));
+        let b: Tensor<TestBackendInt, 1> =
+            Tensor::from_data(Data::new(vec![3, 1], Shape::new([2])));
+
+        assert_eq!(a.bitand(&b).into_data().value, vec![2, 1]);
+    }
+
+    #[test]
+    fn shift_left_scalar_matches_hand_computed_output() {
+        let a: Tensor<TestBackendInt, 1> =
+            Tensor::from_data(Data::new(vec![1, 2], Shape::new([2])));
+
+        assert_eq!(a.shift_left_scalar(&2).into_data().value, vec![4, 8]);
+    }
+
+    #[test]
+    fn adaptive_pool_windows_matches_floor_ceil_formula() {
+        assert_eq!(adaptive_pool_windows(4, 2), vec![(0, 2), (2, 4)]);
+        assert_eq!(adaptive_pool_windows(5, 2), vec![(0, 3), (2, 5)]);
+    }
+
+    #[test]
+    fn adaptive_avg_pool2d_matches_hand_computed_window_averages() {
+        #[rustfmt::skip]
+        let x: Tensor<TestBackend, 4> = Tensor::from_data(Data::new(
+            vec![
+                1.0, 2.0, 3.0, 4.0,
+                5.0, 6.0, 7.0, 8.0,
+                9.0, 10.0, 11.0, 12.0,
+                13.0, 14.0, 15.0, 16.0,
+            ],
+            Shape::new([1, 1, 4, 4]),
+        ));
+
+        let output = x.adaptive_avg_pool2d([2, 2]);
+
+        assert_eq!(output.shape().dims, [1, 1, 2, 2]);
+        assert_eq!(output.into_data().value, vec![3.5, 5.5, 11.5, 13.5]);
+    }
+
+    #[test]
+    fn adaptive_max_pool2d_matches_hand_computed_window_maxima() {
+        #[rustfmt::skip]
+        let x: Tensor<TestBackend, 4> = Tensor::from_data(Data::new(
+            vec![
+                1.0, 2.0, 3.0, 4.0,
+                5.0, 6.0, 7.0, 8.0,
+                9.0, 10.0, 11.0, 12.0,
+                13.0, 14.0, 15.0, 16.0,
+            ],
+            Shape::new([1, 1, 4, 4]),
+        ));
+
+        let output = x.adaptive_max_pool2d([2, 2]);
+
+        assert_eq!(output.shape().dims, [1, 1, 2, 2]);
+        assert_eq!(output.into_data().value, vec![6.0, 8.0, 14.0, 16.0]);
+    }
+
+    #[test]
+    fn adaptive_avg_pool3d_matches_hand_computed_window_average() {
+        let x: Tensor<TestBackend, 5> = Tensor::from_data(Data::new(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            Shape::new([1, 1, 2, 2, 2]),
+        ));
+
+        let output = x.adaptive_avg_pool3d([1, 1, 1]);
+
+        assert_eq!(output.shape().dims, [1, 1, 1, 1, 1]);
+        assert_eq!(output.into_data().value, vec![4.5]);
+    }
+
+    #[test]
+    fn adaptive_max_pool3d_matches_hand_computed_window_maximum() {
+        let x: Tensor<TestBackend, 5> = Tensor::from_data(Data::new(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            Shape::new([1, 1, 2, 2, 2]),
+        ));
+
+        let output = x.adaptive_max_pool3d([1, 1, 1]);
+
+        assert_eq!(output.shape().dims, [1, 1, 1, 1, 1]);
+        assert_eq!(output.into_data().value, vec![8.0]);
+    }
 }
\ No newline at end of file